@@ -0,0 +1,82 @@
+// Calling convention and symbol-decoration rules, keyed off `TargetPlatform`.
+
+use crate::config::{Arch, CompilerOptions, Issue, PlatformName, TargetPlatform};
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum CallingConvention {
+    SystemV,
+    MicrosoftX64,
+    Cdecl,
+}
+
+impl TargetPlatform {
+    // Matches on `arch` explicitly rather than just its pointer width, so an
+    // ARM/RISC-V target can't silently fall through to an x86 convention
+    // whose register names don't mean anything on that architecture.
+    pub(crate) fn calling_convention(&self) -> Result<CallingConvention, Issue> {
+        match self.arch {
+            Arch::x86_32 => Ok(CallingConvention::Cdecl),
+            Arch::x86_64 => Ok(match self.platform_name {
+                PlatformName::Windows => CallingConvention::MicrosoftX64,
+                PlatformName::Linux | PlatformName::Bsd | PlatformName::MacOs =>
+                    CallingConvention::SystemV,
+            }),
+            Arch::Arm | Arch::Aarch64 | Arch::Riscv32 | Arch::Riscv64 =>
+                Err(Issue::UnsupportedAbi(self.arch.to_string())),
+        }
+    }
+}
+
+impl CallingConvention {
+    // Ordered integer/pointer argument registers. B has no floating-point
+    // type, so every argument - including the variadic stdlib functions such
+    // as `printf`/`concat` - goes through this list before spilling to the
+    // stack.
+    pub(crate) fn integer_argument_registers(self) -> &'static [&'static str] {
+        match self {
+            CallingConvention::SystemV => &["rdi", "rsi", "rdx", "rcx", "r8", "r9"],
+            CallingConvention::MicrosoftX64 => &["rcx", "rdx", "r8", "r9"],
+            CallingConvention::Cdecl => &[],
+        }
+    }
+
+    // System V requires `%al` to hold the number of vector registers used by
+    // a variadic call; B has no floating-point type so it is always zero.
+    // The other conventions have no such requirement.
+    pub(crate) fn variadic_vector_register_count(self) -> Option<u8> {
+        match self {
+            CallingConvention::SystemV => Some(0),
+            CallingConvention::MicrosoftX64 | CallingConvention::Cdecl => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum SymbolDecoration {
+    None,
+    LeadingUnderscore,
+}
+
+impl TargetPlatform {
+    pub(crate) fn symbol_decoration(&self) -> SymbolDecoration {
+        match self.platform_name {
+            PlatformName::MacOs => SymbolDecoration::LeadingUnderscore,
+            PlatformName::Windows if self.arch.pointer_width() == 32 =>
+                SymbolDecoration::LeadingUnderscore,
+            _ => SymbolDecoration::None,
+        }
+    }
+}
+
+impl SymbolDecoration {
+    pub(crate) fn decorate(self, symbol: &str) -> String {
+        match self {
+            SymbolDecoration::None => symbol.to_string(),
+            SymbolDecoration::LeadingUnderscore => format!("_{}", symbol),
+        }
+    }
+}
+
+pub(crate) fn decorated_symbol(options: &CompilerOptions, symbol: &str) -> String {
+    options.target_platform.symbol_decoration().decorate(symbol)
+}
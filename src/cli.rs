@@ -0,0 +1,40 @@
+use crate::config::{CompilerOptions, OutputKind, RelocationModel, TargetPlatform};
+
+// Minimal hand-rolled argument parser: the compiler has no crate dependencies
+// yet, so this mirrors the plain `&str` matching style used elsewhere (see
+// `config::get_reserved_symbols`) rather than pulling in a CLI framework.
+pub(crate) fn parse_args<I: Iterator<Item=String>>(mut args: I) -> Result<CompilerOptions, String> {
+    let mut options = CompilerOptions::default();
+    let mut relocation_model_override = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--target" => {
+                let triple = args.next()
+                    .ok_or_else(|| "--target requires a value".to_string())?;
+                options.target_platform = triple.parse::<TargetPlatform>()
+                    .map_err(|_| format!("unrecognized target triple '{}'", triple))?;
+            }
+            "--shared" => {
+                options.output_kind = OutputKind::SharedLibrary;
+            }
+            "--relocation-model" => {
+                let model = args.next()
+                    .ok_or_else(|| "--relocation-model requires a value".to_string())?;
+                relocation_model_override = Some(
+                    model.parse::<RelocationModel>()
+                        .map_err(|_| format!("unrecognized relocation model '{}'", model))?
+                );
+            }
+            _ => {}
+        }
+    }
+
+    // The per-target default depends on `--target`/`--shared`, so it's only
+    // resolved once the whole command line has been read, unless the user
+    // picked one explicitly with `--relocation-model`.
+    options.relocation_model = relocation_model_override
+        .unwrap_or_else(|| options.target_platform.default_relocation_model(options.output_kind));
+
+    Ok(options)
+}
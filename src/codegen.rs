@@ -0,0 +1,38 @@
+// Lowers a B function call to target assembly using the ABI (see `abi`).
+
+use crate::abi::decorated_symbol;
+use crate::config::{CompilerOptions, Issue};
+
+pub(crate) fn emit_call(
+    options: &CompilerOptions,
+    fn_name: &str,
+    args: &[String],
+    is_variadic: bool,
+) -> Result<Vec<String>, Issue> {
+    let convention = options.target_platform.calling_convention()?;
+    let registers = convention.integer_argument_registers();
+    let mut instructions = vec![];
+
+    for (i, arg) in args.iter().enumerate() {
+        match registers.get(i) {
+            Some(register) => instructions.push(format!("mov {}, {}", register, arg)),
+            None => instructions.push(format!("push {}", arg)),
+        }
+    }
+
+    if is_variadic {
+        if let Some(vector_registers) = convention.variadic_vector_register_count() {
+            instructions.push(format!("mov al, {}", vector_registers));
+        }
+    }
+
+    let symbol = decorated_symbol(options, fn_name);
+    let call_target = if options.relocation_model.needs_got_indirection() {
+        format!("{}@PLT", symbol)
+    } else {
+        symbol
+    };
+    instructions.push(format!("call {}", call_target));
+
+    Ok(instructions)
+}
@@ -1,6 +1,7 @@
 use std::*;
 use collections::{HashMap, HashSet};
 use fmt;
+use str::FromStr;
 
 use crate::parser::ast::*;
 use crate::lexical_analyzer::token;
@@ -11,6 +12,15 @@ pub(crate) enum Issue {
     BracketNotClosed(TokenPos),
     EmptyTokenStream,
     ParsingError,
+    UnknownArch(String),
+    UnknownTargetOs(String),
+    UnknownTargetEnv(String),
+    UnknownRelocationModel(String),
+    AssemblerNotFound,
+    AssemblerFailed(String),
+    LinkerNotFound,
+    LinkerFailed(String),
+    UnsupportedAbi(String),
     NameNotDefined {
         name: String,
         pos: TokenPos,
@@ -25,13 +35,72 @@ pub(crate) enum Issue {
     EmptyCompound(CompoundStatementNode),
 }
 
+impl Issue {
+    // The position to point a caret at, for the variants that carry a
+    // `TokenPos` directly. Variants whose position would have to be dug out
+    // of an AST node (`StandardNameRedefined` and friends) aren't covered
+    // yet, so they render as a plain message with no source snippet.
+    pub(crate) fn pos(&self) -> Option<TokenPos> {
+        use Issue::*;
+        match self {
+            BracketNotOpened(pos) | BracketNotClosed(pos) => Some(*pos),
+            NameNotDefined { pos, .. } => Some(*pos),
+            NameRedefined { curr_def_pos, .. } => Some(*curr_def_pos),
+            InitVarWithItself(_, pos) => Some(*pos),
+            _ => None,
+        }
+    }
+
+    // The previous-definition position for variants that report a conflict
+    // against an earlier declaration, rendered as a secondary "note".
+    pub(crate) fn prev_pos(&self) -> Option<TokenPos> {
+        match self {
+            Issue::NameRedefined { prev_def_pos, .. } => *prev_def_pos,
+            _ => None,
+        }
+    }
+
+    // Width, in columns, of the token `pos()`/`prev_pos()` point at, so the
+    // renderer can underline the whole token instead of just its first
+    // character. Brackets are always a single character; for name-carrying
+    // variants it's the identifier's length.
+    pub(crate) fn token_len(&self) -> usize {
+        match self {
+            Issue::NameNotDefined { name, .. } | Issue::NameRedefined { name, .. } =>
+                name.len().max(1),
+            Issue::InitVarWithItself(def, _) => def.name().len().max(1),
+            _ => 1,
+        }
+    }
+}
+
 impl fmt::Display for Issue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use Issue::*;
         let msg =
             match self {
-                ParsingError => "Failed to parse",
-                _ => todo!()
+                BracketNotOpened(_) => "unmatched closing bracket".to_string(),
+                BracketNotClosed(_) => "unclosed bracket".to_string(),
+                EmptyTokenStream => "no tokens to parse".to_string(),
+                ParsingError => "Failed to parse".to_string(),
+                UnknownArch(arch) => format!("unknown target architecture '{}'", arch),
+                UnknownTargetOs(os) => format!("unknown target operating system '{}'", os),
+                UnknownTargetEnv(env) => format!("unknown target environment '{}'", env),
+                UnknownRelocationModel(model) => format!("unknown relocation model '{}'", model),
+                AssemblerNotFound => "could not locate an assembler for this target".to_string(),
+                AssemblerFailed(message) => format!("assembler invocation failed: {}", message),
+                LinkerNotFound => "could not locate a linker for this target".to_string(),
+                LinkerFailed(message) => format!("linker invocation failed: {}", message),
+                UnsupportedAbi(arch) => format!("no calling convention is defined for {} yet", arch),
+                NameNotDefined { name, .. } => format!("name `{}` is not defined", name),
+                NameRedefined { name, .. } => format!("name `{}` is redefined", name),
+                InitVarWithItself(_, _) => "a variable is initialized with itself".to_string(),
+                StandardNameRedefined(_) => "redefinition of a standard library name".to_string(),
+                VecWithNoSizeAndInits(_) =>
+                    "vector declared with neither a size nor initializers".to_string(),
+                VecSizeIsNotANumber(_) => "vector size is not a number".to_string(),
+                FnBodyIsNullStatement(_) => "function body is an empty statement".to_string(),
+                EmptyCompound(_) => "empty compound statement".to_string(),
             };
 
         write!(f, "{}", msg)
@@ -43,6 +112,10 @@ impl fmt::Display for Issue {
 pub(crate) enum Arch {
     x86_32,
     x86_64,
+    Arm,
+    Aarch64,
+    Riscv32,
+    Riscv64,
 }
 
 impl fmt::Display for Arch {
@@ -50,10 +123,61 @@ impl fmt::Display for Arch {
         match self {
             Arch::x86_32 => write!(f, "x86-32"),
             Arch::x86_64 => write!(f, "x86-64"),
+            Arch::Arm => write!(f, "arm"),
+            Arch::Aarch64 => write!(f, "aarch64"),
+            Arch::Riscv32 => write!(f, "riscv32"),
+            Arch::Riscv64 => write!(f, "riscv64"),
+        }
+    }
+}
+
+impl FromStr for Arch {
+    type Err = Issue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "x86_32" | "i386" | "i486" | "i586" | "i686" => Ok(Arch::x86_32),
+            "x86_64" | "amd64" => Ok(Arch::x86_64),
+            "arm" => Ok(Arch::Arm),
+            "aarch64" => Ok(Arch::Aarch64),
+            "riscv32" => Ok(Arch::Riscv32),
+            "riscv64" => Ok(Arch::Riscv64),
+            _ => Err(Issue::UnknownArch(s.to_string())),
         }
     }
 }
 
+impl Arch {
+    // The B language is word-oriented (vectors, `getvec`, word-addressed
+    // memory in the standard library, see `get_standard_library_names`), so
+    // codegen needs a single authoritative place to ask how wide a word is
+    // on the selected target.
+    pub(crate) fn pointer_width(self) -> usize {
+        match self {
+            Arch::x86_32 | Arch::Arm | Arch::Riscv32 => 32,
+            Arch::x86_64 | Arch::Aarch64 | Arch::Riscv64 => 64,
+        }
+    }
+
+    pub(crate) fn endianness(self) -> Endianness {
+        match self {
+            Arch::x86_32
+            | Arch::x86_64
+            | Arch::Arm
+            | Arch::Aarch64
+            | Arch::Riscv32
+            | Arch::Riscv64 => Endianness::Little,
+        }
+    }
+}
+
+// All currently-supported architectures are little-endian; add `Big` back
+// once a big-endian target is actually supported.
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Endianness {
+    Little,
+}
+
 #[warn(non_camel_case_types)]
 #[derive(Copy, Clone, PartialEq)]
 pub(crate) enum PlatformName {
@@ -74,10 +198,45 @@ impl fmt::Display for PlatformName {
     }
 }
 
+impl FromStr for PlatformName {
+    type Err = Issue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linux" => Ok(PlatformName::Linux),
+            "freebsd" | "openbsd" | "netbsd" | "dragonfly" => Ok(PlatformName::Bsd),
+            "windows" => Ok(PlatformName::Windows),
+            "darwin" | "macos" => Ok(PlatformName::MacOs),
+            _ => Err(Issue::UnknownTargetOs(s.to_string())),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum TargetEnv {
+    Gnu,
+    Msvc,
+    Musl,
+}
+
+impl FromStr for TargetEnv {
+    type Err = Issue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "gnu" => Ok(TargetEnv::Gnu),
+            "msvc" => Ok(TargetEnv::Msvc),
+            "musl" => Ok(TargetEnv::Musl),
+            _ => Err(Issue::UnknownTargetEnv(s.to_string())),
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct TargetPlatform {
     pub(crate) platform_name: PlatformName,
     pub(crate) arch: Arch,
+    pub(crate) env: Option<TargetEnv>,
 }
 
 impl TargetPlatform {
@@ -102,32 +261,143 @@ impl TargetPlatform {
                 default_platform
             },
             arch: {
-                if cfg!(target_pointer_width = "32") {
+                if cfg!(target_arch = "x86") {
                     Arch::x86_32
-                } else if cfg!(target_pointer_width = "64") {
+                } else if cfg!(target_arch = "x86_64") {
                     Arch::x86_64
+                } else if cfg!(target_arch = "arm") {
+                    Arch::Arm
+                } else if cfg!(target_arch = "aarch64") {
+                    Arch::Aarch64
+                } else if cfg!(target_arch = "riscv32") {
+                    Arch::Riscv32
+                } else if cfg!(target_arch = "riscv64") {
+                    Arch::Riscv64
                 } else {
                     let default_arch = TargetPlatform::default().arch;
                     println!("Failed to determine the native architecture. Assuming it's {}", default_arch);
                     default_arch
                 }
             },
+            env: None,
         }
     }
 }
 
+impl FromStr for TargetPlatform {
+    type Err = Issue;
+
+    // Accepts canonical GNU-style triples of the form `arch-vendor-os[-env]`,
+    // e.g. `x86_64-pc-windows-gnu`, `i686-unknown-linux-gnu`, `x86_64-apple-darwin`.
+    // The vendor field (and a missing/`unknown` one) is ignored; the trailing
+    // `env` field is kept when it is `gnu`, `msvc` or `musl`, since toolchain
+    // discovery needs it. Any other `env` field is dropped, leaving `env` as `None`.
+    fn from_str(triple: &str) -> Result<Self, Self::Err> {
+        let mut fields = triple.split('-');
+
+        let arch = fields.next()
+            .ok_or_else(|| Issue::UnknownTargetOs(triple.to_string()))?
+            .parse::<Arch>()?;
+
+        let remaining: Vec<&str> = fields.filter(|&field| field != "unknown").collect();
+
+        let os_index = remaining.iter()
+            .position(|field| field.parse::<PlatformName>().is_ok())
+            .ok_or_else(|| Issue::UnknownTargetOs(triple.to_string()))?;
+
+        let platform_name = remaining[os_index].parse::<PlatformName>()?;
+        let env = remaining.get(os_index + 1).and_then(|field| field.parse::<TargetEnv>().ok());
+
+        Ok(TargetPlatform { platform_name, arch, env })
+    }
+}
+
 impl Default for TargetPlatform {
     fn default() -> Self {
         TargetPlatform {
             platform_name: PlatformName::Linux,
             arch: Arch::x86_64,
+            env: None,
         }
     }
 }
 
-#[derive(Default, Copy, Clone)]
+impl TargetPlatform {
+    // 64-bit Linux/BSD toolchains and modern hardened distros expect
+    // position-independent code by default; 32-bit x86 historically needs
+    // `-fPIC` passed explicitly to avoid text-relocation regressions when
+    // the output is linked into a shared object. Shared-library output
+    // itself always needs PIC, regardless of target.
+    pub(crate) fn default_relocation_model(&self, output_kind: OutputKind) -> RelocationModel {
+        if output_kind == OutputKind::SharedLibrary {
+            return RelocationModel::Pic;
+        }
+
+        match self.platform_name {
+            PlatformName::Linux | PlatformName::Bsd if self.arch.pointer_width() == 64 =>
+                RelocationModel::Pic,
+            _ => RelocationModel::Static,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum OutputKind {
+    Executable,
+    SharedLibrary,
+}
+
+impl Default for OutputKind {
+    fn default() -> Self {
+        OutputKind::Executable
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum RelocationModel {
+    Static,
+    Pic,
+    Pie,
+}
+
+impl RelocationModel {
+    // Whether the assembly/emit stage should address globals through a
+    // GOT/PLT-style indirection rather than a direct absolute address.
+    pub(crate) fn needs_got_indirection(self) -> bool {
+        matches!(self, RelocationModel::Pic | RelocationModel::Pie)
+    }
+}
+
+impl FromStr for RelocationModel {
+    type Err = Issue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "static" => Ok(RelocationModel::Static),
+            "pic" => Ok(RelocationModel::Pic),
+            "pie" => Ok(RelocationModel::Pie),
+            _ => Err(Issue::UnknownRelocationModel(s.to_string())),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
 pub(crate) struct CompilerOptions {
     pub(crate) target_platform: TargetPlatform,
+    pub(crate) output_kind: OutputKind,
+    pub(crate) relocation_model: RelocationModel,
+}
+
+impl Default for CompilerOptions {
+    fn default() -> Self {
+        let target_platform = TargetPlatform::default();
+        let output_kind = OutputKind::default();
+        CompilerOptions {
+            relocation_model: target_platform.default_relocation_model(output_kind),
+            target_platform,
+            output_kind,
+        }
+    }
 }
 
 pub(crate) fn get_escape_sequences() -> HashMap<String, String> {
@@ -0,0 +1,42 @@
+// Renders an `Issue` GCC/Clang-style: `file:line:col: error: <message>`, the
+// source line, and a caret underline.
+
+use crate::config::Issue;
+use crate::lexical_analyzer::token::TokenPos;
+
+pub(crate) fn render(issue: &Issue, file_name: &str, source: &str) -> String {
+    let mut out = String::new();
+    let token_len = issue.token_len();
+
+    match issue.pos() {
+        Some(pos) =>
+            render_at(&mut out, file_name, source, pos, token_len, "error", &issue.to_string()),
+        None => out.push_str(&format!("{}: error: {}\n", file_name, issue)),
+    }
+
+    if let Some(prev_pos) = issue.prev_pos() {
+        render_at(&mut out, file_name, source, prev_pos, token_len, "note", "previous definition here");
+    }
+
+    out
+}
+
+fn render_at(
+    out: &mut String,
+    file_name: &str,
+    source: &str,
+    pos: TokenPos,
+    token_len: usize,
+    level: &str,
+    message: &str,
+) {
+    out.push_str(&format!("{}:{}:{}: {}: {}\n", file_name, pos.line, pos.column, level, message));
+
+    if let Some(line) = source.lines().nth(pos.line.saturating_sub(1)) {
+        out.push_str(line);
+        out.push('\n');
+        out.push_str(&" ".repeat(pos.column.saturating_sub(1)));
+        out.push_str(&"^".repeat(token_len.max(1)));
+        out.push('\n');
+    }
+}
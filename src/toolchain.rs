@@ -0,0 +1,165 @@
+// Locates and invokes an assembler and linker for a `TargetPlatform`.
+
+use std::{env, process};
+use std::path::PathBuf;
+
+use crate::config::{Issue, PlatformName, TargetEnv, TargetPlatform};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ToolKind {
+    Assembler,
+    Linker,
+}
+
+impl ToolKind {
+    fn not_found(self) -> Issue {
+        match self {
+            ToolKind::Assembler => Issue::AssemblerNotFound,
+            ToolKind::Linker => Issue::LinkerNotFound,
+        }
+    }
+
+    fn failed(self, message: String) -> Issue {
+        match self {
+            ToolKind::Assembler => Issue::AssemblerFailed(message),
+            ToolKind::Linker => Issue::LinkerFailed(message),
+        }
+    }
+}
+
+pub(crate) struct Tool {
+    pub(crate) path: PathBuf,
+    pub(crate) args: Vec<String>,
+    pub(crate) kind: ToolKind,
+}
+
+impl Tool {
+    pub(crate) fn invoke(&self, extra_args: &[String]) -> Result<(), Issue> {
+        let status = process::Command::new(&self.path)
+            .args(&self.args)
+            .args(extra_args)
+            .status();
+
+        match status {
+            Ok(status) if status.success() => Ok(()),
+            Ok(status) => Err(self.kind.failed(format!("exited with {}", status))),
+            Err(e) => Err(self.kind.failed(e.to_string())),
+        }
+    }
+}
+
+pub(crate) fn find_assembler(target: &TargetPlatform) -> Result<Tool, Issue> {
+    find_tool(target, ToolKind::Assembler)
+}
+
+pub(crate) fn find_linker(target: &TargetPlatform) -> Result<Tool, Issue> {
+    find_tool(target, ToolKind::Linker)
+}
+
+fn find_tool(target: &TargetPlatform, kind: ToolKind) -> Result<Tool, Issue> {
+    match target.platform_name {
+        PlatformName::Windows => find_windows_tool(target, kind),
+        PlatformName::Linux | PlatformName::Bsd | PlatformName::MacOs => find_unix_tool(kind),
+    }
+}
+
+// Unix-like platforms: search PATH, honoring `CC`/`AS`/`LD` env overrides
+// before falling back to the usual names, same as a plain Makefile would.
+fn find_unix_tool(kind: ToolKind) -> Result<Tool, Issue> {
+    let mut candidates = vec![];
+
+    let kind_specific_env = match kind {
+        ToolKind::Assembler => "AS",
+        ToolKind::Linker => "LD",
+    };
+    if let Ok(over) = env::var(kind_specific_env) {
+        candidates.push(over);
+    }
+    if let Ok(cc) = env::var("CC") {
+        candidates.push(cc);
+    }
+    candidates.extend(
+        match kind {
+            ToolKind::Assembler => ["cc", "gcc", "as"],
+            ToolKind::Linker => ["cc", "gcc", "ld"],
+        }.iter().map(|s| s.to_string())
+    );
+
+    candidates.iter()
+        .find_map(|name| find_in_path(name))
+        .map(|path| Tool { path, args: vec![], kind })
+        .ok_or_else(|| kind.not_found())
+}
+
+// Windows: an explicit `-gnu` target env goes straight to the MinGW `gcc`
+// toolchain; anything else prefers an installed MSVC toolchain (found on
+// PATH, as a developer command prompt sets it up, or under the standard
+// Visual Studio install roots), falling back to MinGW if none is found.
+fn find_windows_tool(target: &TargetPlatform, kind: ToolKind) -> Result<Tool, Issue> {
+    if target.env == Some(TargetEnv::Gnu) {
+        return find_unix_tool(kind);
+    }
+
+    if let Some(tool) = find_msvc_tool(target, kind) {
+        return Ok(tool);
+    }
+
+    find_unix_tool(kind)
+}
+
+fn find_msvc_tool(target: &TargetPlatform, kind: ToolKind) -> Option<Tool> {
+    let exe = match kind {
+        // MASM comes in a 32-bit (`ml.exe`) and a 64-bit (`ml64.exe`) build;
+        // picking the wrong one would happily assemble for the wrong target.
+        ToolKind::Assembler if target.arch.pointer_width() == 32 => "ml.exe",
+        ToolKind::Assembler => "ml64.exe",
+        ToolKind::Linker => "link.exe",
+    };
+
+    if let Some(path) = find_in_path(exe) {
+        return Some(Tool { path, args: vec![], kind });
+    }
+
+    const INSTALL_ROOTS: [&str; 2] = [
+        r"C:\Program Files\Microsoft Visual Studio",
+        r"C:\Program Files (x86)\Microsoft Visual Studio",
+    ];
+
+    INSTALL_ROOTS.iter()
+        .map(PathBuf::from)
+        .filter(|root| root.is_dir())
+        .find_map(|root| find_file_named(&root, exe, 4))
+        .map(|path| Tool { path, args: vec![], kind })
+}
+
+fn find_in_path(name: &str) -> Option<PathBuf> {
+    let path_var = env::var_os("PATH")?;
+    // Bare names like `gcc` also match `gcc.exe` on a Windows host.
+    env::split_paths(&path_var)
+        .flat_map(|dir| vec![dir.join(name), dir.join(format!("{}{}", name, env::consts::EXE_SUFFIX))])
+        .find(|candidate| candidate.is_file())
+}
+
+// Depth-limited recursive search, since a Visual Studio install root nests
+// the actual `link.exe`/`ml64.exe` several directories deep under a version
+// and host/target architecture pair we can't predict ahead of time.
+fn find_file_named(dir: &std::path::Path, name: &str, max_depth: u32) -> Option<PathBuf> {
+    if max_depth == 0 {
+        return None;
+    }
+
+    let entries = std::fs::read_dir(dir).ok()?;
+    let mut subdirs = vec![];
+
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.is_file() && path.file_name().map_or(false, |f| f == name) {
+            return Some(path);
+        }
+        if path.is_dir() {
+            subdirs.push(path);
+        }
+    }
+
+    subdirs.iter().find_map(|subdir| find_file_named(subdir, name, max_depth - 1))
+}